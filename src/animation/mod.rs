@@ -67,6 +67,21 @@ where
     get_buffer_data: F,
 }
 
+/// A TRS (or morph weight) value produced by evaluating a channel's sampler
+/// at a point in time via [`Reader::sample`].
+#[cfg(feature = "utils")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransformValue {
+    /// An interpolated translation.
+    Translation([f32; 3]),
+    /// An interpolated rotation quaternion, in `[x, y, z, w]` order.
+    Rotation([f32; 4]),
+    /// An interpolated scale.
+    Scale([f32; 3]),
+    /// Interpolated morph target weights.
+    MorphTargetWeights(Vec<f32>),
+}
+
 impl<'a> Animation<'a> {
     /// Constructs an `Animation`.
     pub(crate) fn new(
@@ -116,6 +131,205 @@ impl<'a> Animation<'a> {
             iter: self.json.samplers.iter(),
         }
     }
+
+    /// Resamples every channel of this animation to a fixed frame rate,
+    /// producing uniform per-frame TRS (and morph weight) tracks grouped by
+    /// the node each channel targets.
+    ///
+    /// The sampled range spans the minimum and maximum keyframe input value
+    /// across all of this animation's samplers, stepped at `1.0 / fps`. Each
+    /// frame reuses the same STEP/LINEAR/CUBICSPLINE evaluation as
+    /// `Reader::sample`, which is useful for runtimes that can't evaluate
+    /// splines at draw time and want a flat keyframe buffer instead.
+    ///
+    /// A channel whose buffer data can't be read, or whose output accessor
+    /// doesn't hold exactly one (triplet, for `CUBICSPLINE`) keyframe per
+    /// input keyframe, is skipped rather than failing the whole bake (see
+    /// `BakedOutputs::read`).
+    ///
+    /// Returns an empty `Vec` if `fps` is not positive or none of the
+    /// channels' input accessors could be read.
+    #[cfg(feature = "utils")]
+    pub fn bake<'s, F>(&self, fps: f32, get_buffer_data: F) -> Vec<(scene::Node<'a>, BakedTrack)>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        use std::collections::HashMap;
+
+        if !(fps > 0.0) {
+            return Vec::new();
+        }
+
+        // Decode each channel's keyframes once up front rather than inside
+        // the per-frame loop, so baking doesn't re-read and re-collect the
+        // same accessor on every frame.
+        let prepared: Vec<_> = self
+            .channels()
+            .filter_map(|channel| {
+                let reader = channel.reader(get_buffer_data.clone());
+                let inputs: Vec<f32> = reader.read_inputs()?.collect();
+                if inputs.is_empty() {
+                    return None;
+                }
+                let interpolation = channel.sampler().interpolation();
+                let outputs = BakedOutputs::read(&reader, &inputs)?;
+                let node = self.document.nodes().nth(channel.json.target.node.value()).unwrap();
+                Some((node, inputs, interpolation, outputs))
+            })
+            .collect();
+        if prepared.is_empty() {
+            return Vec::new();
+        }
+
+        let start = prepared.iter().map(|&(_, ref inputs, ..)| inputs[0]).fold(f32::INFINITY, f32::min);
+        let end = prepared
+            .iter()
+            .map(|&(_, ref inputs, ..)| *inputs.last().unwrap())
+            .fold(f32::NEG_INFINITY, f32::max);
+
+        let frame_count = (((end - start) * fps).round() as usize) + 1;
+        let mut tracks: Vec<(scene::Node<'a>, BakedTrack)> = Vec::new();
+        let mut track_by_node: HashMap<usize, usize> = HashMap::new();
+        for frame in 0..frame_count {
+            let t = (start + frame as f32 / fps).min(end);
+            for &(ref node, ref inputs, interpolation, ref outputs) in &prepared {
+                let t = t.max(inputs[0]).min(*inputs.last().unwrap());
+                let i = if inputs.len() > 1 { segment(inputs, t) } else { 0 };
+                let value = outputs.sample(inputs, interpolation, i, t);
+                let index = *track_by_node.entry(node.index()).or_insert_with(|| {
+                    tracks.push((node.clone(), BakedTrack::default()));
+                    tracks.len() - 1
+                });
+                tracks[index].1.set(frame, value);
+            }
+        }
+        tracks
+    }
+}
+
+/// Pre-decoded, normalized channel output keyframes, reused across every
+/// frame of `Animation::bake` instead of re-reading the accessor each time.
+#[cfg(feature = "utils")]
+enum BakedOutputs {
+    Translation(Vec<[f32; 3]>),
+    CubicTranslation(Vec<util::CubicKeyframe<[f32; 3]>>),
+    Rotation(Vec<[f32; 4]>),
+    CubicRotation(Vec<util::CubicKeyframe<[f32; 4]>>),
+    Scale(Vec<[f32; 3]>),
+    CubicScale(Vec<util::CubicKeyframe<[f32; 3]>>),
+    Weights(Vec<f32>, usize),
+    CubicWeights(Vec<util::CubicKeyframe<Vec<f32>>>, usize),
+}
+
+#[cfg(feature = "utils")]
+impl BakedOutputs {
+    fn read<'a, 's, F>(reader: &Reader<'a, 's, F>, inputs: &[f32]) -> Option<Self>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Some(match reader.read_outputs()? {
+            util::ReadOutputs::Translations(iter) => {
+                BakedOutputs::Translation(require_len(iter.collect(), inputs.len())?)
+            }
+            util::ReadOutputs::CubicTranslations(iter) => {
+                BakedOutputs::CubicTranslation(require_len(iter.collect(), inputs.len())?)
+            }
+            util::ReadOutputs::Scales(iter) => {
+                BakedOutputs::Scale(require_len(iter.collect(), inputs.len())?)
+            }
+            util::ReadOutputs::CubicScales(iter) => {
+                BakedOutputs::CubicScale(require_len(iter.collect(), inputs.len())?)
+            }
+            util::ReadOutputs::Rotations(rotations) => {
+                BakedOutputs::Rotation(require_len(normalize_rotations(rotations), inputs.len())?)
+            }
+            util::ReadOutputs::CubicRotations(rotations) => {
+                let keyframes = require_len(normalize_cubic_rotations(rotations), inputs.len())?;
+                BakedOutputs::CubicRotation(keyframes)
+            }
+            util::ReadOutputs::MorphTargetWeights(weights) => {
+                let values = normalize_weights(weights);
+                if values.is_empty() || values.len() % inputs.len() != 0 {
+                    return None;
+                }
+                let count = values.len() / inputs.len();
+                BakedOutputs::Weights(values, count)
+            }
+            util::ReadOutputs::CubicMorphTargetWeights(weights) => {
+                let keyframes = require_len(normalize_cubic_weights(weights), inputs.len())?;
+                let count = keyframes.get(0).map_or(0, |keyframe| keyframe.value.len());
+                BakedOutputs::CubicWeights(keyframes, count)
+            }
+        })
+    }
+
+    fn sample(&self, inputs: &[f32], interpolation: Interpolation, i: usize, t: f32) -> TransformValue {
+        match *self {
+            BakedOutputs::Translation(ref v) => {
+                TransformValue::Translation(sample_vec3(inputs, v, interpolation, i, t))
+            }
+            BakedOutputs::CubicTranslation(ref v) => {
+                TransformValue::Translation(sample_cubic_vec3(inputs, v, i, t))
+            }
+            BakedOutputs::Scale(ref v) => {
+                TransformValue::Scale(sample_vec3(inputs, v, interpolation, i, t))
+            }
+            BakedOutputs::CubicScale(ref v) => {
+                TransformValue::Scale(sample_cubic_vec3(inputs, v, i, t))
+            }
+            BakedOutputs::Rotation(ref v) => {
+                TransformValue::Rotation(sample_rotation(inputs, v, interpolation, i, t))
+            }
+            BakedOutputs::CubicRotation(ref v) => {
+                TransformValue::Rotation(sample_cubic_rotation(inputs, v, i, t))
+            }
+            BakedOutputs::Weights(ref v, count) => {
+                TransformValue::MorphTargetWeights(sample_weights(inputs, v, count, interpolation, i, t))
+            }
+            BakedOutputs::CubicWeights(ref v, count) => {
+                TransformValue::MorphTargetWeights(sample_cubic_weights(inputs, v, count, i, t))
+            }
+        }
+    }
+}
+
+/// A uniformly-sampled TRS (and morph weight) track produced by
+/// `Animation::bake`, one entry per property that was actually targeted by a
+/// channel.
+#[cfg(feature = "utils")]
+#[derive(Clone, Debug, Default)]
+pub struct BakedTrack {
+    /// Per-frame translations, present if a translation channel targeted the node.
+    pub translations: Option<Vec<[f32; 3]>>,
+    /// Per-frame rotations (in `[x, y, z, w]` order), present if a rotation
+    /// channel targeted the node.
+    pub rotations: Option<Vec<[f32; 4]>>,
+    /// Per-frame scales, present if a scale channel targeted the node.
+    pub scales: Option<Vec<[f32; 3]>>,
+    /// Per-frame morph target weights, present if a weights channel targeted
+    /// the node.
+    pub morph_target_weights: Option<Vec<Vec<f32>>>,
+}
+
+#[cfg(feature = "utils")]
+impl BakedTrack {
+    fn set(&mut self, frame: usize, value: TransformValue) {
+        fn put<T: Clone>(track: &mut Option<Vec<T>>, frame: usize, default: T, value: T) {
+            let frames = track.get_or_insert_with(Vec::new);
+            if frames.len() <= frame {
+                frames.resize(frame + 1, default);
+            }
+            frames[frame] = value;
+        }
+        match value {
+            TransformValue::Translation(v) => put(&mut self.translations, frame, [0.0; 3], v),
+            TransformValue::Rotation(v) => put(&mut self.rotations, frame, [0.0, 0.0, 0.0, 1.0], v),
+            TransformValue::Scale(v) => put(&mut self.scales, frame, [1.0; 3], v),
+            TransformValue::MorphTargetWeights(v) => {
+                put(&mut self.morph_target_weights, frame, Vec::new(), v)
+            }
+        }
+    }
 }
 
 impl<'a> Channel<'a> {
@@ -179,39 +393,525 @@ where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
     }
 
     /// Visits the output samples of a channel.
+    ///
+    /// When the channel's sampler uses `CUBICSPLINE` interpolation, the
+    /// output accessor stores three elements per keyframe (`inTangent`,
+    /// `value`, `outTangent`); this is reported via the `Cubic*` variants,
+    /// already grouped into [`util::CubicKeyframe`] triplets, rather than a
+    /// flat iterator yielding 3x the expected number of elements.
     pub fn read_outputs(&self) -> Option<util::ReadOutputs<'s>> {
         use accessor::{DataType, Iter};
         use animation::Property;
-        use self::util::{Rotations, ReadOutputs, MorphTargetWeights};
+        use self::util::{
+            CubicKeyframes, CubicRotations, CubicMorphTargetWeights, CubicWeightsKeyframes,
+            Rotations, ReadOutputs, MorphTargetWeights,
+        };
 
         let output = self.channel.sampler().output();
+        let is_cubic_spline = self.channel.sampler().interpolation() == Interpolation::CubicSpline;
         if let Some(slice) = (self.get_buffer_data)(output.view().buffer()) {
             Some(
                 match self.channel.target().property() {
-                    Property::Translation => ReadOutputs::Translations(Iter::new(output, slice)),
-                    Property::Rotation => ReadOutputs::Rotations(match output.data_type() {
-                        DataType::I8 => Rotations::I8(Iter::new(output, slice)),
-                        DataType::U8 => Rotations::U8(Iter::new(output, slice)),
-                        DataType::I16 => Rotations::I16(Iter::new(output, slice)),
-                        DataType::U16 => Rotations::U16(Iter::new(output, slice)),
-                        DataType::F32 => Rotations::F32(Iter::new(output, slice)),
-                        _ => unreachable!()
-                    }),
-                    Property::Scale => ReadOutputs::Scales(Iter::new(output, slice)),
-                    Property::MorphTargetWeights => ReadOutputs::MorphTargetWeights(match output.data_type() {
-                        DataType::I8 => MorphTargetWeights::I8(Iter::new(output, slice)),
-                        DataType::U8 => MorphTargetWeights::U8(Iter::new(output, slice)),
-                        DataType::I16 => MorphTargetWeights::I16(Iter::new(output, slice)),
-                        DataType::U16 => MorphTargetWeights::U16(Iter::new(output, slice)),
-                        DataType::F32 => MorphTargetWeights::F32(Iter::new(output, slice)),
-                        _ => unreachable!()
-                    }),
+                    Property::Translation => if is_cubic_spline {
+                        ReadOutputs::CubicTranslations(CubicKeyframes::new(Iter::new(output, slice)))
+                    } else {
+                        ReadOutputs::Translations(Iter::new(output, slice))
+                    },
+                    Property::Rotation => if is_cubic_spline {
+                        ReadOutputs::CubicRotations(match output.data_type() {
+                            DataType::I8 => CubicRotations::I8(CubicKeyframes::new(Iter::new(output, slice))),
+                            DataType::U8 => CubicRotations::U8(CubicKeyframes::new(Iter::new(output, slice))),
+                            DataType::I16 => CubicRotations::I16(CubicKeyframes::new(Iter::new(output, slice))),
+                            DataType::U16 => CubicRotations::U16(CubicKeyframes::new(Iter::new(output, slice))),
+                            DataType::F32 => CubicRotations::F32(CubicKeyframes::new(Iter::new(output, slice))),
+                            _ => unreachable!()
+                        })
+                    } else {
+                        ReadOutputs::Rotations(match output.data_type() {
+                            DataType::I8 => Rotations::I8(Iter::new(output, slice)),
+                            DataType::U8 => Rotations::U8(Iter::new(output, slice)),
+                            DataType::I16 => Rotations::I16(Iter::new(output, slice)),
+                            DataType::U16 => Rotations::U16(Iter::new(output, slice)),
+                            DataType::F32 => Rotations::F32(Iter::new(output, slice)),
+                            _ => unreachable!()
+                        })
+                    },
+                    Property::Scale => if is_cubic_spline {
+                        ReadOutputs::CubicScales(CubicKeyframes::new(Iter::new(output, slice)))
+                    } else {
+                        ReadOutputs::Scales(Iter::new(output, slice))
+                    },
+                    Property::MorphTargetWeights => if is_cubic_spline {
+                        let input_count = self.channel.sampler().input().count();
+                        let count = if input_count > 0 { output.count() / (3 * input_count) } else { 0 };
+                        ReadOutputs::CubicMorphTargetWeights(match output.data_type() {
+                            DataType::I8 => CubicMorphTargetWeights::I8(CubicWeightsKeyframes::new(Iter::new(output, slice), count)),
+                            DataType::U8 => CubicMorphTargetWeights::U8(CubicWeightsKeyframes::new(Iter::new(output, slice), count)),
+                            DataType::I16 => CubicMorphTargetWeights::I16(CubicWeightsKeyframes::new(Iter::new(output, slice), count)),
+                            DataType::U16 => CubicMorphTargetWeights::U16(CubicWeightsKeyframes::new(Iter::new(output, slice), count)),
+                            DataType::F32 => CubicMorphTargetWeights::F32(CubicWeightsKeyframes::new(Iter::new(output, slice), count)),
+                            _ => unreachable!()
+                        })
+                    } else {
+                        ReadOutputs::MorphTargetWeights(match output.data_type() {
+                            DataType::I8 => MorphTargetWeights::I8(Iter::new(output, slice)),
+                            DataType::U8 => MorphTargetWeights::U8(Iter::new(output, slice)),
+                            DataType::I16 => MorphTargetWeights::I16(Iter::new(output, slice)),
+                            DataType::U16 => MorphTargetWeights::U16(Iter::new(output, slice)),
+                            DataType::F32 => MorphTargetWeights::F32(Iter::new(output, slice)),
+                            _ => unreachable!()
+                        })
+                    },
                 }
-            )            
+            )
         } else {
             None
         }
     }
+
+    /// Evaluates the channel's sampler at time `t`, returning the
+    /// interpolated TRS (or morph weight) value.
+    ///
+    /// `t` is clamped to the sampler's keyframe range. The interpolation mode
+    /// is chosen according to `Sampler::interpolation()`; `CUBICSPLINE`
+    /// samplers store their output accessor as `[inTangent, value, outTangent]`
+    /// triplets per keyframe, which are reshaped into a Hermite curve here.
+    ///
+    /// Returns `None` if the channel's buffer data could not be read, or if
+    /// its output accessor doesn't hold exactly one (triplet, for
+    /// `CUBICSPLINE`) keyframe per element of its input accessor.
+    pub fn sample(&self, t: f32) -> Option<TransformValue> {
+        let inputs: Vec<f32> = self.read_inputs()?.collect();
+        if inputs.is_empty() {
+            return None;
+        }
+        let t = t.max(inputs[0]).min(*inputs.last().unwrap());
+        let interpolation = self.channel.sampler().interpolation();
+        let i = if inputs.len() > 1 { segment(&inputs, t) } else { 0 };
+        Some(match self.read_outputs()? {
+            util::ReadOutputs::Translations(iter) => TransformValue::Translation(
+                sample_vec3(&inputs, &require_len(iter.collect(), inputs.len())?, interpolation, i, t),
+            ),
+            util::ReadOutputs::CubicTranslations(iter) => TransformValue::Translation(
+                sample_cubic_vec3(&inputs, &require_len(iter.collect(), inputs.len())?, i, t),
+            ),
+            util::ReadOutputs::Scales(iter) => TransformValue::Scale(
+                sample_vec3(&inputs, &require_len(iter.collect(), inputs.len())?, interpolation, i, t),
+            ),
+            util::ReadOutputs::CubicScales(iter) => TransformValue::Scale(
+                sample_cubic_vec3(&inputs, &require_len(iter.collect(), inputs.len())?, i, t),
+            ),
+            util::ReadOutputs::Rotations(rotations) => {
+                let values = require_len(normalize_rotations(rotations), inputs.len())?;
+                TransformValue::Rotation(sample_rotation(&inputs, &values, interpolation, i, t))
+            }
+            util::ReadOutputs::CubicRotations(rotations) => {
+                let keyframes = require_len(normalize_cubic_rotations(rotations), inputs.len())?;
+                TransformValue::Rotation(sample_cubic_rotation(&inputs, &keyframes, i, t))
+            }
+            util::ReadOutputs::MorphTargetWeights(weights) => {
+                let values = normalize_weights(weights);
+                if values.is_empty() || values.len() % inputs.len() != 0 {
+                    return None;
+                }
+                let count = values.len() / inputs.len();
+                TransformValue::MorphTargetWeights(
+                    sample_weights(&inputs, &values, count, interpolation, i, t),
+                )
+            }
+            util::ReadOutputs::CubicMorphTargetWeights(weights) => {
+                let keyframes = require_len(normalize_cubic_weights(weights), inputs.len())?;
+                let count = keyframes[0].value.len();
+                TransformValue::MorphTargetWeights(
+                    sample_cubic_weights(&inputs, &keyframes, count, i, t),
+                )
+            }
+        })
+    }
+}
+
+/// Returns `values` unchanged if it has exactly `expected` elements, or
+/// `None` otherwise.
+///
+/// A channel's output accessor is supposed to hold exactly one (triplet, for
+/// `CUBICSPLINE`) keyframe per element of its input accessor; a malformed
+/// asset (bad exporter, truncated buffer) can disagree, which would
+/// otherwise let the `sample_*`/`sample_cubic_*` helpers index past the end
+/// of `values` for any `t` beyond the first keyframe.
+#[cfg(feature = "utils")]
+fn require_len<T>(values: Vec<T>, expected: usize) -> Option<Vec<T>> {
+    if values.len() == expected {
+        Some(values)
+    } else {
+        None
+    }
+}
+
+/// Returns the index `i` such that `inputs[i] <= t < inputs[i + 1]`, found by
+/// binary search and clamped to the last valid segment.
+#[cfg(feature = "utils")]
+fn segment(inputs: &[f32], t: f32) -> usize {
+    match inputs.binary_search_by(|probe| probe.partial_cmp(&t).unwrap()) {
+        Ok(i) => i.min(inputs.len() - 2),
+        Err(0) => 0,
+        Err(i) => (i - 1).min(inputs.len() - 2),
+    }
+}
+
+#[cfg(feature = "utils")]
+fn lerp3(a: [f32; 3], b: [f32; 3], s: f32) -> [f32; 3] {
+    [
+        a[0] + (b[0] - a[0]) * s,
+        a[1] + (b[1] - a[1]) * s,
+        a[2] + (b[2] - a[2]) * s,
+    ]
+}
+
+#[cfg(feature = "utils")]
+fn hermite3(v0: [f32; 3], out0: [f32; 3], v1: [f32; 3], in1: [f32; 3], td: f32, s: f32) -> [f32; 3] {
+    let (h00, h10, h01, h11) = hermite_basis(td, s);
+    [
+        h00 * v0[0] + h10 * out0[0] + h01 * v1[0] + h11 * in1[0],
+        h00 * v0[1] + h10 * out0[1] + h01 * v1[1] + h11 * in1[1],
+        h00 * v0[2] + h10 * out0[2] + h01 * v1[2] + h11 * in1[2],
+    ]
+}
+
+#[cfg(feature = "utils")]
+fn hermite_basis(td: f32, s: f32) -> (f32, f32, f32, f32) {
+    let s2 = s * s;
+    let s3 = s2 * s;
+    let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+    let h10 = td * (s3 - 2.0 * s2 + s);
+    let h01 = -2.0 * s3 + 3.0 * s2;
+    let h11 = td * (s3 - s2);
+    (h00, h10, h01, h11)
+}
+
+#[cfg(feature = "utils")]
+fn sample_vec3(
+    inputs: &[f32],
+    values: &[[f32; 3]],
+    interpolation: Interpolation,
+    i: usize,
+    t: f32,
+) -> [f32; 3] {
+    if inputs.len() == 1 {
+        return values[0];
+    }
+    match interpolation {
+        Interpolation::Step => values[i],
+        Interpolation::Linear => {
+            let td = inputs[i + 1] - inputs[i];
+            let s = if td > 0.0 { (t - inputs[i]) / td } else { 0.0 };
+            lerp3(values[i], values[i + 1], s)
+        }
+        Interpolation::CubicSpline => unreachable!("cubic spline outputs are read pre-grouped"),
+    }
+}
+
+#[cfg(feature = "utils")]
+fn sample_cubic_vec3(
+    inputs: &[f32],
+    keyframes: &[util::CubicKeyframe<[f32; 3]>],
+    i: usize,
+    t: f32,
+) -> [f32; 3] {
+    if inputs.len() == 1 {
+        return keyframes[0].value;
+    }
+    let td = inputs[i + 1] - inputs[i];
+    let s = if td > 0.0 { (t - inputs[i]) / td } else { 0.0 };
+    hermite3(
+        keyframes[i].value,
+        keyframes[i].out_tangent,
+        keyframes[i + 1].value,
+        keyframes[i + 1].in_tangent,
+        td,
+        s,
+    )
+}
+
+#[cfg(feature = "utils")]
+fn normalize_quat(q: [f32; 4]) -> [f32; 4] {
+    let len = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+    if len > 0.0 {
+        [q[0] / len, q[1] / len, q[2] / len, q[3] / len]
+    } else {
+        q
+    }
+}
+
+#[cfg(feature = "utils")]
+fn lerp4(a: [f32; 4], b: [f32; 4], s: f32) -> [f32; 4] {
+    [
+        a[0] + (b[0] - a[0]) * s,
+        a[1] + (b[1] - a[1]) * s,
+        a[2] + (b[2] - a[2]) * s,
+        a[3] + (b[3] - a[3]) * s,
+    ]
+}
+
+#[cfg(feature = "utils")]
+fn slerp(a: [f32; 4], b: [f32; 4], s: f32) -> [f32; 4] {
+    let a = normalize_quat(a);
+    let mut b = normalize_quat(b);
+    let mut dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+    if dot < 0.0 {
+        b = [-b[0], -b[1], -b[2], -b[3]];
+        dot = -dot;
+    }
+    if dot > 0.9995 {
+        // The endpoints are nearly identical; slerp's sin(theta) divisor
+        // would be near zero, so fall back to a normalized lerp.
+        return normalize_quat(lerp4(a, b, s));
+    }
+    let theta_0 = dot.acos();
+    let theta = theta_0 * s;
+    let sin_theta_0 = theta_0.sin();
+    let s0 = (theta_0 - theta).sin() / sin_theta_0;
+    let s1 = theta.sin() / sin_theta_0;
+    [
+        a[0] * s0 + b[0] * s1,
+        a[1] * s0 + b[1] * s1,
+        a[2] * s0 + b[2] * s1,
+        a[3] * s0 + b[3] * s1,
+    ]
+}
+
+#[cfg(feature = "utils")]
+fn hermite4(v0: [f32; 4], out0: [f32; 4], v1: [f32; 4], in1: [f32; 4], td: f32, s: f32) -> [f32; 4] {
+    let (h00, h10, h01, h11) = hermite_basis(td, s);
+    [
+        h00 * v0[0] + h10 * out0[0] + h01 * v1[0] + h11 * in1[0],
+        h00 * v0[1] + h10 * out0[1] + h01 * v1[1] + h11 * in1[1],
+        h00 * v0[2] + h10 * out0[2] + h01 * v1[2] + h11 * in1[2],
+        h00 * v0[3] + h10 * out0[3] + h01 * v1[3] + h11 * in1[3],
+    ]
+}
+
+#[cfg(feature = "utils")]
+fn sample_rotation(
+    inputs: &[f32],
+    values: &[[f32; 4]],
+    interpolation: Interpolation,
+    i: usize,
+    t: f32,
+) -> [f32; 4] {
+    if inputs.len() == 1 {
+        return normalize_quat(values[0]);
+    }
+    match interpolation {
+        Interpolation::Step => normalize_quat(values[i]),
+        Interpolation::Linear => {
+            let td = inputs[i + 1] - inputs[i];
+            let s = if td > 0.0 { (t - inputs[i]) / td } else { 0.0 };
+            slerp(values[i], values[i + 1], s)
+        }
+        Interpolation::CubicSpline => unreachable!("cubic spline outputs are read pre-grouped"),
+    }
+}
+
+#[cfg(feature = "utils")]
+fn sample_cubic_rotation(
+    inputs: &[f32],
+    keyframes: &[util::CubicKeyframe<[f32; 4]>],
+    i: usize,
+    t: f32,
+) -> [f32; 4] {
+    if inputs.len() == 1 {
+        return normalize_quat(keyframes[0].value);
+    }
+    let td = inputs[i + 1] - inputs[i];
+    let s = if td > 0.0 { (t - inputs[i]) / td } else { 0.0 };
+    normalize_quat(hermite4(
+        keyframes[i].value,
+        keyframes[i].out_tangent,
+        keyframes[i + 1].value,
+        keyframes[i + 1].in_tangent,
+        td,
+        s,
+    ))
+}
+
+#[cfg(feature = "utils")]
+fn normalize_rotations<'s>(rotations: util::Rotations<'s>) -> Vec<[f32; 4]> {
+    match rotations {
+        util::Rotations::I8(iter) => iter
+            .map(|[x, y, z, w]: [i8; 4]| {
+                [
+                    (x as f32 / i8::max_value() as f32).max(-1.0),
+                    (y as f32 / i8::max_value() as f32).max(-1.0),
+                    (z as f32 / i8::max_value() as f32).max(-1.0),
+                    (w as f32 / i8::max_value() as f32).max(-1.0),
+                ]
+            })
+            .collect(),
+        util::Rotations::U8(iter) => iter
+            .map(|[x, y, z, w]: [u8; 4]| {
+                [
+                    x as f32 / u8::max_value() as f32,
+                    y as f32 / u8::max_value() as f32,
+                    z as f32 / u8::max_value() as f32,
+                    w as f32 / u8::max_value() as f32,
+                ]
+            })
+            .collect(),
+        util::Rotations::I16(iter) => iter
+            .map(|[x, y, z, w]: [i16; 4]| {
+                [
+                    (x as f32 / i16::max_value() as f32).max(-1.0),
+                    (y as f32 / i16::max_value() as f32).max(-1.0),
+                    (z as f32 / i16::max_value() as f32).max(-1.0),
+                    (w as f32 / i16::max_value() as f32).max(-1.0),
+                ]
+            })
+            .collect(),
+        util::Rotations::U16(iter) => iter
+            .map(|[x, y, z, w]: [u16; 4]| {
+                [
+                    x as f32 / u16::max_value() as f32,
+                    y as f32 / u16::max_value() as f32,
+                    z as f32 / u16::max_value() as f32,
+                    w as f32 / u16::max_value() as f32,
+                ]
+            })
+            .collect(),
+        util::Rotations::F32(iter) => iter.collect(),
+    }
+}
+
+#[cfg(feature = "utils")]
+fn normalize_weights<'s>(weights: util::MorphTargetWeights<'s>) -> Vec<f32> {
+    match weights {
+        util::MorphTargetWeights::I8(iter) => {
+            iter.map(|x: i8| (x as f32 / i8::max_value() as f32).max(-1.0)).collect()
+        }
+        util::MorphTargetWeights::U8(iter) => {
+            iter.map(|x: u8| x as f32 / u8::max_value() as f32).collect()
+        }
+        util::MorphTargetWeights::I16(iter) => {
+            iter.map(|x: i16| (x as f32 / i16::max_value() as f32).max(-1.0)).collect()
+        }
+        util::MorphTargetWeights::U16(iter) => {
+            iter.map(|x: u16| x as f32 / u16::max_value() as f32).collect()
+        }
+        util::MorphTargetWeights::F32(iter) => iter.collect(),
+    }
+}
+
+#[cfg(feature = "utils")]
+fn sample_weights(
+    inputs: &[f32],
+    values: &[f32],
+    count: usize,
+    interpolation: Interpolation,
+    i: usize,
+    t: f32,
+) -> Vec<f32> {
+    if inputs.len() == 1 {
+        return values[..count].to_vec();
+    }
+    match interpolation {
+        Interpolation::Step => values[i * count..(i + 1) * count].to_vec(),
+        Interpolation::Linear => {
+            let td = inputs[i + 1] - inputs[i];
+            let s = if td > 0.0 { (t - inputs[i]) / td } else { 0.0 };
+            let a = &values[i * count..(i + 1) * count];
+            let b = &values[(i + 1) * count..(i + 2) * count];
+            a.iter().zip(b).map(|(a, b)| a + (b - a) * s).collect()
+        }
+        Interpolation::CubicSpline => unreachable!("cubic spline outputs are read pre-grouped"),
+    }
+}
+
+#[cfg(feature = "utils")]
+fn sample_cubic_weights(
+    inputs: &[f32],
+    keyframes: &[util::CubicKeyframe<Vec<f32>>],
+    count: usize,
+    i: usize,
+    t: f32,
+) -> Vec<f32> {
+    if inputs.len() == 1 {
+        return keyframes[0].value.clone();
+    }
+    let td = inputs[i + 1] - inputs[i];
+    let s = if td > 0.0 { (t - inputs[i]) / td } else { 0.0 };
+    let (h00, h10, h01, h11) = hermite_basis(td, s);
+    let (v0, out0) = (&keyframes[i].value, &keyframes[i].out_tangent);
+    let (v1, in1) = (&keyframes[i + 1].value, &keyframes[i + 1].in_tangent);
+    (0..count)
+        .map(|k| h00 * v0[k] + h10 * out0[k] + h01 * v1[k] + h11 * in1[k])
+        .collect()
+}
+
+#[cfg(feature = "utils")]
+fn normalize_cubic_rotations<'s>(rotations: util::CubicRotations<'s>) -> Vec<util::CubicKeyframe<[f32; 4]>> {
+    fn norm<T, I>(iter: util::CubicKeyframes<I>, scale: f32, signed: bool) -> Vec<util::CubicKeyframe<[f32; 4]>>
+    where
+        T: Copy + Into<f32>,
+        I: Iterator<Item = [T; 4]>,
+    {
+        iter.map(|keyframe| {
+            let convert = |v: [T; 4]| {
+                let q = [v[0].into() / scale, v[1].into() / scale, v[2].into() / scale, v[3].into() / scale];
+                if signed {
+                    [q[0].max(-1.0), q[1].max(-1.0), q[2].max(-1.0), q[3].max(-1.0)]
+                } else {
+                    q
+                }
+            };
+            util::CubicKeyframe {
+                in_tangent: convert(keyframe.in_tangent),
+                value: convert(keyframe.value),
+                out_tangent: convert(keyframe.out_tangent),
+            }
+        }).collect()
+    }
+    match rotations {
+        util::CubicRotations::I8(iter) => norm(iter, i8::max_value() as f32, true),
+        util::CubicRotations::U8(iter) => norm(iter, u8::max_value() as f32, false),
+        util::CubicRotations::I16(iter) => norm(iter, i16::max_value() as f32, true),
+        util::CubicRotations::U16(iter) => norm(iter, u16::max_value() as f32, false),
+        util::CubicRotations::F32(iter) => iter.map(|keyframe| util::CubicKeyframe {
+            in_tangent: keyframe.in_tangent,
+            value: keyframe.value,
+            out_tangent: keyframe.out_tangent,
+        }).collect(),
+    }
+}
+
+#[cfg(feature = "utils")]
+fn normalize_cubic_weights<'s>(weights: util::CubicMorphTargetWeights<'s>) -> Vec<util::CubicKeyframe<Vec<f32>>> {
+    fn norm<T, I>(iter: util::CubicWeightsKeyframes<I>, scale: f32, signed: bool) -> Vec<util::CubicKeyframe<Vec<f32>>>
+    where
+        T: Copy + Into<f32>,
+        I: Iterator<Item = T>,
+    {
+        iter.map(|keyframe| {
+            let convert = |v: Vec<T>| -> Vec<f32> {
+                v.into_iter()
+                    .map(|x| {
+                        let x = x.into() / scale;
+                        if signed { x.max(-1.0) } else { x }
+                    })
+                    .collect()
+            };
+            util::CubicKeyframe {
+                in_tangent: convert(keyframe.in_tangent),
+                value: convert(keyframe.value),
+                out_tangent: convert(keyframe.out_tangent),
+            }
+        }).collect()
+    }
+    match weights {
+        util::CubicMorphTargetWeights::I8(iter) => norm(iter, i8::max_value() as f32, true),
+        util::CubicMorphTargetWeights::U8(iter) => norm(iter, u8::max_value() as f32, false),
+        util::CubicMorphTargetWeights::I16(iter) => norm(iter, i16::max_value() as f32, true),
+        util::CubicMorphTargetWeights::U16(iter) => norm(iter, u16::max_value() as f32, false),
+        util::CubicMorphTargetWeights::F32(iter) => iter.collect(),
+    }
 }
 
 impl<'a> Target<'a> {
@@ -0,0 +1,193 @@
+use accessor::Iter;
+
+/// Rotation animations are split into groups by output component type.
+#[derive(Clone, Debug)]
+pub enum Rotations<'a> {
+    /// Rotations of type `[i8; 4]`.
+    I8(Iter<'a, [i8; 4]>),
+    /// Rotations of type `[u8; 4]`.
+    U8(Iter<'a, [u8; 4]>),
+    /// Rotations of type `[i16; 4]`.
+    I16(Iter<'a, [i16; 4]>),
+    /// Rotations of type `[u16; 4]`.
+    U16(Iter<'a, [u16; 4]>),
+    /// Rotations of type `[f32; 4]`.
+    F32(Iter<'a, [f32; 4]>),
+}
+
+/// Morph target weights are split into groups by output component type.
+#[derive(Clone, Debug)]
+pub enum MorphTargetWeights<'a> {
+    /// Weights of type `i8`.
+    I8(Iter<'a, i8>),
+    /// Weights of type `u8`.
+    U8(Iter<'a, u8>),
+    /// Weights of type `i16`.
+    I16(Iter<'a, i16>),
+    /// Weights of type `u16`.
+    U16(Iter<'a, u16>),
+    /// Weights of type `f32`.
+    F32(Iter<'a, f32>),
+}
+
+/// Keyframe input (time) samples of a channel.
+pub type ReadInputs<'a> = Iter<'a, f32>;
+
+/// A single `CUBICSPLINE` keyframe, grouping the incoming tangent, value, and
+/// outgoing tangent that the output accessor stores as three consecutive
+/// elements, so callers don't have to reshape the flat accessor themselves.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicKeyframe<T> {
+    /// The incoming tangent.
+    pub in_tangent: T,
+    /// The keyframe value.
+    pub value: T,
+    /// The outgoing tangent.
+    pub out_tangent: T,
+}
+
+/// An `Iterator` that groups a flat `CUBICSPLINE` output accessor into
+/// [`CubicKeyframe`] triplets, consuming three source elements per keyframe.
+#[derive(Clone, Debug)]
+pub struct CubicKeyframes<I> {
+    iter: I,
+}
+
+impl<I> CubicKeyframes<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter: iter }
+    }
+}
+
+impl<T, I> Iterator for CubicKeyframes<I>
+where
+    I: Iterator<Item = T>,
+{
+    type Item = CubicKeyframe<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // A truncated accessor (fewer than 3 elements left) yields `None`
+        // rather than panicking, so malformed or corrupted buffer data can't
+        // crash a caller that merely collects this iterator.
+        let in_tangent = self.iter.next()?;
+        let value = self.iter.next()?;
+        let out_tangent = self.iter.next()?;
+        Some(CubicKeyframe {
+            in_tangent: in_tangent,
+            value: value,
+            out_tangent: out_tangent,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo / 3, hi.map(|hi| hi / 3))
+    }
+}
+
+/// Grouped `CUBICSPLINE` rotation keyframes, split by output component type.
+#[derive(Clone, Debug)]
+pub enum CubicRotations<'a> {
+    /// Rotations of type `[i8; 4]`.
+    I8(CubicKeyframes<Iter<'a, [i8; 4]>>),
+    /// Rotations of type `[u8; 4]`.
+    U8(CubicKeyframes<Iter<'a, [u8; 4]>>),
+    /// Rotations of type `[i16; 4]`.
+    I16(CubicKeyframes<Iter<'a, [i16; 4]>>),
+    /// Rotations of type `[u16; 4]`.
+    U16(CubicKeyframes<Iter<'a, [u16; 4]>>),
+    /// Rotations of type `[f32; 4]`.
+    F32(CubicKeyframes<Iter<'a, [f32; 4]>>),
+}
+
+/// An `Iterator` that groups a flat `CUBICSPLINE` morph target weights
+/// output accessor into [`CubicKeyframe`] triplets of `count`-wide weight
+/// vectors, `count * 3` source elements per keyframe.
+///
+/// Unlike translations/rotations/scales, a morph weights keyframe is itself a
+/// vector of `count` weights (one per morph target), so the triplet grouping
+/// has to know `count` up front rather than grouping every three elements.
+#[derive(Clone, Debug)]
+pub struct CubicWeightsKeyframes<I> {
+    iter: I,
+    count: usize,
+}
+
+impl<I> CubicWeightsKeyframes<I> {
+    pub(crate) fn new(iter: I, count: usize) -> Self {
+        Self { iter: iter, count: count }
+    }
+}
+
+impl<T, I> Iterator for CubicWeightsKeyframes<I>
+where
+    I: Iterator<Item = T>,
+{
+    type Item = CubicKeyframe<Vec<T>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // A zero-width weights vector carries no keyframes to yield, and
+        // `take(iter, 0)` would otherwise succeed trivially without ever
+        // polling `self.iter`, looping forever in any caller that collects
+        // this iterator.
+        if self.count == 0 {
+            return None;
+        }
+        fn take<T, I: Iterator<Item = T>>(iter: &mut I, n: usize) -> Option<Vec<T>> {
+            let mut values = Vec::with_capacity(n);
+            for _ in 0..n {
+                values.push(iter.next()?);
+            }
+            Some(values)
+        }
+        // A truncated accessor (fewer elements left than a full keyframe
+        // needs) yields `None` rather than panicking.
+        let in_tangent = take(&mut self.iter, self.count)?;
+        let value = take(&mut self.iter, self.count)?;
+        let out_tangent = take(&mut self.iter, self.count)?;
+        Some(CubicKeyframe {
+            in_tangent: in_tangent,
+            value: value,
+            out_tangent: out_tangent,
+        })
+    }
+}
+
+/// Grouped `CUBICSPLINE` morph target weight keyframes, split by output
+/// component type.
+#[derive(Clone, Debug)]
+pub enum CubicMorphTargetWeights<'a> {
+    /// Weights of type `i8`.
+    I8(CubicWeightsKeyframes<Iter<'a, i8>>),
+    /// Weights of type `u8`.
+    U8(CubicWeightsKeyframes<Iter<'a, u8>>),
+    /// Weights of type `i16`.
+    I16(CubicWeightsKeyframes<Iter<'a, i16>>),
+    /// Weights of type `u16`.
+    U16(CubicWeightsKeyframes<Iter<'a, u16>>),
+    /// Weights of type `f32`.
+    F32(CubicWeightsKeyframes<Iter<'a, f32>>),
+}
+
+/// Animation channel output samples, grouped by target property. When the
+/// channel's sampler uses `CUBICSPLINE` interpolation, the `Cubic*` variants
+/// are yielded instead of the flat ones, already reshaped into
+/// [`CubicKeyframe`] triplets rather than a stream of 3x the expected
+/// elements.
+#[derive(Clone, Debug)]
+pub enum ReadOutputs<'a> {
+    /// Translation keyframes.
+    Translations(Iter<'a, [f32; 3]>),
+    /// `CUBICSPLINE` translation keyframes.
+    CubicTranslations(CubicKeyframes<Iter<'a, [f32; 3]>>),
+    /// Rotation keyframes.
+    Rotations(Rotations<'a>),
+    /// `CUBICSPLINE` rotation keyframes.
+    CubicRotations(CubicRotations<'a>),
+    /// Scale keyframes.
+    Scales(Iter<'a, [f32; 3]>),
+    /// `CUBICSPLINE` scale keyframes.
+    CubicScales(CubicKeyframes<Iter<'a, [f32; 3]>>),
+    /// Morph target weight keyframes.
+    MorphTargetWeights(MorphTargetWeights<'a>),
+    /// `CUBICSPLINE` morph target weight keyframes.
+    CubicMorphTargetWeights(CubicMorphTargetWeights<'a>),
+}
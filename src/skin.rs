@@ -138,6 +138,158 @@ where
 
         None
     }
+
+    /// Computes the per-joint skinning matrices used to deform a mesh on the
+    /// CPU or to upload as a bone palette, aligned with `Skin::joints()`
+    /// order.
+    ///
+    /// `mesh_transform` is the world-space (global) transform of the node the
+    /// skin is attached to, and `joint_transform` maps a joint node to its
+    /// own world-space transform. For joint `j` the resulting matrix is
+    ///
+    /// ```text
+    /// inverse(mesh_transform) * joint_transform(joints[j]) * inverse_bind_matrices[j]
+    /// ```
+    ///
+    /// The inverse-bind matrix defaults to the identity matrix when the skin
+    /// provides none, per `Skin::inverse_bind_matrices()`.
+    ///
+    /// Returns `None` if the skin does provide an inverse-bind matrices
+    /// accessor but its buffer data could not be read; this is distinct from
+    /// the skin simply having no such accessor, which is not an error.
+    pub fn read_skinning_matrices<G>(
+        &self,
+        mesh_transform: [[f32; 4]; 4],
+        joint_transform: G,
+    ) -> Option<SkinningMatrices<'a, 's, G>>
+    where
+        G: FnMut(Node<'a>) -> [[f32; 4]; 4],
+    {
+        let inverse_bind_matrices = if self.skin.inverse_bind_matrices().is_some() {
+            Some(self.read_inverse_bind_matrices()?)
+        } else {
+            None
+        };
+        Some(SkinningMatrices {
+            joints: self.skin.joints(),
+            inverse_bind_matrices: inverse_bind_matrices,
+            inverse_mesh_transform: invert_mat4(mesh_transform),
+            joint_transform: joint_transform,
+        })
+    }
+}
+
+/// An `Iterator` that computes the per-joint skinning matrices of a `Skin`,
+/// as produced by `Reader::read_skinning_matrices`.
+#[cfg(feature = "utils")]
+pub struct SkinningMatrices<'a, 's, G>
+where
+    G: FnMut(Node<'a>) -> [[f32; 4]; 4],
+{
+    joints: Joints<'a>,
+    inverse_bind_matrices: Option<ReadInverseBindMatrices<'s>>,
+    inverse_mesh_transform: [[f32; 4]; 4],
+    joint_transform: G,
+}
+
+#[cfg(feature = "utils")]
+impl<'a, 's, G> Iterator for SkinningMatrices<'a, 's, G>
+where
+    G: FnMut(Node<'a>) -> [[f32; 4]; 4],
+{
+    type Item = [[f32; 4]; 4];
+    fn next(&mut self) -> Option<Self::Item> {
+        let joint = self.joints.next()?;
+        let inverse_bind_matrix = self.inverse_bind_matrices
+            .as_mut()
+            .and_then(Iterator::next)
+            .unwrap_or_else(identity_mat4);
+        let joint_transform = (self.joint_transform)(joint);
+        Some(mul_mat4(
+            mul_mat4(self.inverse_mesh_transform, joint_transform),
+            inverse_bind_matrix,
+        ))
+    }
+}
+
+/// Returns the 4x4 identity matrix.
+#[cfg(feature = "utils")]
+fn identity_mat4() -> [[f32; 4]; 4] {
+    [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]
+}
+
+/// Multiplies two column-major 4x4 matrices, `a * b`.
+#[cfg(feature = "utils")]
+fn mul_mat4(a: [[f32; 4]; 4], b: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    let mut out = [[0.0; 4]; 4];
+    for col in 0..4 {
+        for row in 0..4 {
+            out[col][row] = (0..4).map(|k| a[k][row] * b[col][k]).sum();
+        }
+    }
+    out
+}
+
+/// Inverts a column-major 4x4 matrix, falling back to the identity matrix
+/// when it is singular.
+#[cfg(feature = "utils")]
+fn invert_mat4(m: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    let m = [
+        m[0][0], m[0][1], m[0][2], m[0][3],
+        m[1][0], m[1][1], m[1][2], m[1][3],
+        m[2][0], m[2][1], m[2][2], m[2][3],
+        m[3][0], m[3][1], m[3][2], m[3][3],
+    ];
+    let mut inv = [0.0f32; 16];
+    inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+        + m[9] * m[7] * m[14] + m[13] * m[6] * m[11] - m[13] * m[7] * m[10];
+    inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+        - m[8] * m[7] * m[14] - m[12] * m[6] * m[11] + m[12] * m[7] * m[10];
+    inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+        + m[8] * m[7] * m[13] + m[12] * m[5] * m[11] - m[12] * m[7] * m[9];
+    inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+        - m[8] * m[6] * m[13] - m[12] * m[5] * m[10] + m[12] * m[6] * m[9];
+    inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+        - m[9] * m[3] * m[14] - m[13] * m[2] * m[11] + m[13] * m[3] * m[10];
+    inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+        + m[8] * m[3] * m[14] + m[12] * m[2] * m[11] - m[12] * m[3] * m[10];
+    inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+        - m[8] * m[3] * m[13] - m[12] * m[1] * m[11] + m[12] * m[3] * m[9];
+    inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+        + m[8] * m[2] * m[13] + m[12] * m[1] * m[10] - m[12] * m[2] * m[9];
+    inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+        + m[5] * m[3] * m[14] + m[13] * m[2] * m[7] - m[13] * m[3] * m[6];
+    inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+        - m[4] * m[3] * m[14] - m[12] * m[2] * m[7] + m[12] * m[3] * m[6];
+    inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+        + m[4] * m[3] * m[13] + m[12] * m[1] * m[7] - m[12] * m[3] * m[5];
+    inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+        - m[4] * m[2] * m[13] - m[12] * m[1] * m[6] + m[12] * m[2] * m[5];
+    inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+        - m[5] * m[3] * m[10] - m[9] * m[2] * m[7] + m[9] * m[3] * m[6];
+    inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+        + m[4] * m[3] * m[10] + m[8] * m[2] * m[7] - m[8] * m[3] * m[6];
+    inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+        - m[4] * m[3] * m[9] - m[8] * m[1] * m[7] + m[8] * m[3] * m[5];
+    inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+        + m[4] * m[2] * m[9] + m[8] * m[1] * m[6] - m[8] * m[2] * m[5];
+
+    let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+    if det == 0.0 {
+        return identity_mat4();
+    }
+    let det_inv = 1.0 / det;
+    [
+        [inv[0] * det_inv, inv[1] * det_inv, inv[2] * det_inv, inv[3] * det_inv],
+        [inv[4] * det_inv, inv[5] * det_inv, inv[6] * det_inv, inv[7] * det_inv],
+        [inv[8] * det_inv, inv[9] * det_inv, inv[10] * det_inv, inv[11] * det_inv],
+        [inv[12] * det_inv, inv[13] * det_inv, inv[14] * det_inv, inv[15] * det_inv],
+    ]
 }
 
 impl<'a> Iterator for Joints<'a>  {
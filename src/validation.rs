@@ -3,10 +3,82 @@ use std::{error, fmt};
 
 use Gltf;
 
+/// How serious a validation issue is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    /// A hard error; the glTF violates the specification and may not behave
+    /// correctly (or safely) if used as-is.
+    Error,
+    /// A suspicious-but-legal value, or a reference to an unused object.
+    /// Callers may choose to proceed despite these.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A single validation issue, with the JSON pointer path of the value that
+/// triggered it and how serious the problem is.
+#[derive(Debug)]
+pub struct ValidationIssue {
+    path: String,
+    severity: Severity,
+    err: json::validation::Error,
+}
+
+impl ValidationIssue {
+    /// The JSON pointer path of the value that failed validation.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// How serious the issue is.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// The underlying validation error.
+    pub fn error(&self) -> &json::validation::Error {
+        &self.err
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} ({})", self.path, self.err, self.severity)
+    }
+}
+
 /// Validation error type.
+///
+/// Carries every issue found during validation, not just the first one.
+/// `validate_minimally` only ever produces `Severity::Error` issues (the
+/// invariants required for the library to function safely); the additional
+/// checks performed by `validate_completely` produce `Severity::Warning`
+/// issues, and only escalate to an `Err` here when at least one hard error
+/// is also present.
 #[derive(Debug)]
 pub struct Error {
-    errs: Vec<(json::Path, json::validation::Error)>,
+    issues: Vec<ValidationIssue>,
+}
+
+impl Error {
+    /// Returns an iterator over every validation issue, paired with the JSON
+    /// pointer path of the value that triggered it.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &json::validation::Error)> {
+        self.issues.iter().map(|issue| (issue.path(), issue.error()))
+    }
+
+    /// Returns an iterator over every validation issue.
+    pub fn issues(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter()
+    }
 }
 
 /// Represents `glTF` that hasn't been validated yet.
@@ -33,43 +105,62 @@ impl Unvalidated {
     /// Validates only the invariants required for the library to function safely.
     pub fn validate_minimally(self) -> Result<Gltf, Error> {
         use json::validation::Validate;
-        let mut errs = vec![];
+        let mut issues = vec![];
         {
             let json = self.as_json();
             json.validate_minimally(
                 json,
                 json::Path::new,
-                &mut |path, err| errs.push((path(), err)),
+                &mut |path, err| issues.push(ValidationIssue {
+                    path: path().to_string(),
+                    severity: Severity::Error,
+                    err: err,
+                }),
             );
         }
-        if errs.is_empty() {
+        if issues.is_empty() {
             Ok(self.0)
         } else {
-            Err(Error { errs })
+            Err(Error { issues })
         }
     }
 
     /// Validates the data against the `glTF` 2.0 specification.
-    pub fn validate_completely(self) -> Result<Gltf, Error> {
+    ///
+    /// Issues found by the minimal safety pass are hard `Severity::Error`s;
+    /// additional spec-compliance issues (e.g. unused objects, suspicious
+    /// but legal values) are reported as `Severity::Warning` and only fail
+    /// validation when accompanied by at least one hard error. On success,
+    /// any warnings are returned alongside the `Gltf` so callers can inspect
+    /// or log them without being forced to reject the asset.
+    pub fn validate_completely(self) -> Result<(Gltf, Vec<ValidationIssue>), Error> {
         use json::validation::Validate;
-        let mut errs = vec![];
+        let mut issues = vec![];
         {
             let json = self.as_json();
             json.validate_minimally(
                 json,
                 json::Path::new,
-                &mut |path, err| errs.push((path(), err)),
+                &mut |path, err| issues.push(ValidationIssue {
+                    path: path().to_string(),
+                    severity: Severity::Error,
+                    err: err,
+                }),
             );
             json.validate_completely(
                 json,
                 json::Path::new,
-                &mut |path, err| errs.push((path(), err)),
+                &mut |path, err| issues.push(ValidationIssue {
+                    path: path().to_string(),
+                    severity: Severity::Warning,
+                    err: err,
+                }),
             );
         }
-        if errs.is_empty() {
-            Ok(self.0)
+        if issues.iter().any(|issue| issue.severity == Severity::Error) {
+            Err(Error { issues })
         } else {
-            Err(Error { errs })
+            Ok((self.0, issues))
         }
     }
 }
@@ -80,13 +171,20 @@ impl error::Error for Error {
     }
 
     fn cause(&self) -> Option<&error::Error> {
-        self.errs.first().map(|&(_, ref err)| err as &error::Error)
+        self.issues
+            .iter()
+            .find(|issue| issue.severity == Severity::Error)
+            .map(|issue| &issue.err as &error::Error)
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use error::Error;
-        write!(f, "{}", self.description())
+        writeln!(f, "{}:", self.description())?;
+        for issue in &self.issues {
+            writeln!(f, "  {}", issue)?;
+        }
+        Ok(())
     }
 }